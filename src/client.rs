@@ -0,0 +1,360 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_stream::try_stream;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use reqwest::{cookie::CookieStore, Client, Url};
+
+use crate::retry::is_retryable_status;
+use crate::{
+    Error, Item, MylistResponse, MylistsResponse, NicoError, NicoErrorCode, NicoResult, Result,
+    RetryConfig,
+};
+
+const DEFAULT_FRONTEND_ID: &str = "6";
+const DEFAULT_BASE_URL: &str = "https://nvapi.nicovideo.jp";
+const DEFAULT_STREAM_CONCURRENCY: usize = 4;
+
+/// A persistent client for the niconico nvapi.
+///
+/// Unlike the old free functions, a `NicoClient` owns a single, pooled
+/// `reqwest::Client` built once up front, so repeated calls reuse
+/// connections instead of rebuilding one (and re-taking the cookie jar)
+/// per request.
+pub struct NicoClient {
+    http: Client,
+    frontend_id: String,
+    base_url: String,
+    retry: RetryConfig,
+}
+
+impl NicoClient {
+    /// Start building a [`NicoClient`] backed by `cookie_store`.
+    pub fn builder<C: CookieStore + 'static>(cookie_store: Arc<C>) -> NicoClientBuilder<C> {
+        NicoClientBuilder {
+            cookie_store,
+            http_client: None,
+            frontend_id: DEFAULT_FRONTEND_ID.to_string(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            retry: RetryConfig::default(),
+        }
+    }
+
+    /// Fetch the signed-in user's mylists, including up to
+    /// `sample_item_count` sample items per list.
+    pub async fn my_mylists(
+        &self,
+        sample_item_count: usize,
+    ) -> Result<NicoResult<MylistsResponse>> {
+        let url = format!(
+            "{}/v1/users/me/mylists?sampleItemCount={}",
+            self.base_url, sample_item_count
+        )
+        .parse::<Url>()
+        .expect("This is illegal");
+
+        self.get(url).await
+    }
+
+    /// Fetch a single page of `id`'s items.
+    pub async fn mylist(
+        &self,
+        id: usize,
+        page_size: usize,
+        page: usize,
+    ) -> Result<NicoResult<MylistResponse>> {
+        let url = format!(
+            "{}/v1/users/me/mylists/{}?pageSize={}&page={}",
+            self.base_url, id, page_size, page
+        )
+        .parse::<Url>()
+        .expect("This is illegal");
+
+        self.get(url).await
+    }
+
+    /// Fetch every item of `id` by walking all pages serially.
+    pub async fn mylist_all(&self, id: usize) -> Result<NicoResult<MylistResponse>> {
+        let mut first_mylist = self.mylist(id, 100, 1).await?;
+        if !first_mylist.data.mylist.has_next {
+            return Ok(first_mylist);
+        }
+
+        let mut page = 2;
+        let mut extend_items = Vec::new();
+        loop {
+            let next_mylist = self.mylist(id, 100, page).await?;
+            extend_items.extend(next_mylist.data.mylist.items);
+            if !next_mylist.data.mylist.has_next {
+                break;
+            }
+            page += 1;
+        }
+
+        first_mylist.data.mylist.items.extend(extend_items);
+
+        Ok(first_mylist)
+    }
+
+    /// Stream `id`'s items page by page, fetching up to
+    /// [`DEFAULT_STREAM_CONCURRENCY`] pages concurrently while yielding
+    /// items in page order as soon as they're available.
+    pub fn mylist_stream(&self, id: usize, page_size: usize) -> impl Stream<Item = Result<Item>> + '_ {
+        try_stream! {
+            let first = self.mylist(id, page_size, 1).await?;
+            let total_pages = total_pages(first.data.mylist.total_item_count, page_size);
+
+            for item in first.data.mylist.items {
+                yield item;
+            }
+
+            if total_pages > 1 {
+                let mut pages = stream::iter(2..=total_pages)
+                    .map(|page| self.mylist(id, page_size, page))
+                    .buffered(DEFAULT_STREAM_CONCURRENCY);
+
+                while let Some(page) = pages.next().await {
+                    for item in page?.data.mylist.items {
+                        yield item;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fetch every item of `id`, fetching pages with up to `concurrency`
+    /// requests in flight at once instead of walking them serially.
+    pub async fn mylist_all_concurrent(
+        &self,
+        id: usize,
+        concurrency: usize,
+    ) -> Result<NicoResult<MylistResponse>> {
+        // `buffered(0)` never polls its source stream, so a misconfigured
+        // concurrency of 0 would otherwise hang (or silently drop every
+        // page past the first) instead of just running serially.
+        let concurrency = concurrency.max(1);
+
+        let page_size = 100;
+        let mut first_mylist = self.mylist(id, page_size, 1).await?;
+        let total_pages = total_pages(first_mylist.data.mylist.total_item_count, page_size);
+
+        if total_pages > 1 {
+            let pages: Vec<_> = stream::iter(2..=total_pages)
+                .map(|page| self.mylist(id, page_size, page))
+                .buffered(concurrency)
+                .try_collect()
+                .await?;
+
+            for page in pages {
+                first_mylist.data.mylist.items.extend(page.data.mylist.items);
+            }
+        }
+
+        Ok(first_mylist)
+    }
+
+    /// The configured nvapi base URL, exposed so other nvapi-backed
+    /// modules (e.g. [`crate::search`]) can build requests against it.
+    pub(crate) fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub(crate) async fn get<T: serde::de::DeserializeOwned>(&self, url: Url) -> Result<T> {
+        let mut attempt = 0;
+        loop {
+            match self.try_get(url.clone()).await {
+                Ok(value) => return Ok(value),
+                Err(failure) => {
+                    if attempt >= self.retry.max_retries || !failure.is_retryable() {
+                        return Err(failure.error);
+                    }
+                    tokio::time::sleep(self.retry.delay_for(attempt, failure.retry_after)).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    async fn try_get<T: serde::de::DeserializeOwned>(
+        &self,
+        url: Url,
+    ) -> std::result::Result<T, RequestFailure> {
+        let response = self
+            .http
+            .get(url)
+            .header("X-Frontend-Id", &self.frontend_id)
+            .send()
+            .await
+            .map_err(|err| RequestFailure::new(Error::Http(err), None, None))?;
+
+        let status = response.status();
+        let retry_after = parse_retry_after(&response);
+        let string = response
+            .text()
+            .await
+            .map_err(|err| RequestFailure::new(Error::Http(err), Some(status), retry_after))?;
+
+        if status.as_u16() > 299u16 {
+            let err: NicoError = serde_json::from_str(&string).map_err(|err| {
+                RequestFailure::new(Error::Json(err), Some(status), retry_after)
+            })?;
+            return Err(RequestFailure::new(
+                Error::Status(err),
+                Some(status),
+                retry_after,
+            ));
+        }
+
+        serde_json::from_str(&string)
+            .map_err(|err| RequestFailure::new(Error::Json(err), Some(status), retry_after))
+    }
+}
+
+fn total_pages(total_item_count: usize, page_size: usize) -> usize {
+    (total_item_count + page_size - 1) / page_size
+}
+
+/// A failed request, carrying enough context for [`NicoClient::get`] to
+/// decide whether it's worth retrying: the real HTTP status (when a
+/// response was received at all) is checked first, and a retryable status
+/// is enough on its own, whatever the error; a non-JSON error body (e.g.
+/// from a proxy) must not be mistaken for a non-retryable failure just
+/// because it failed to parse as a [`NicoError`].
+struct RequestFailure {
+    error: Error,
+    status: Option<reqwest::StatusCode>,
+    retry_after: Option<Duration>,
+}
+
+impl RequestFailure {
+    fn new(error: Error, status: Option<reqwest::StatusCode>, retry_after: Option<Duration>) -> Self {
+        Self {
+            error,
+            status,
+            retry_after,
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        if self
+            .status
+            .is_some_and(|status| is_retryable_status(status.as_u16()))
+        {
+            return true;
+        }
+
+        match &self.error {
+            Error::Http(err) => err.is_timeout() || err.is_connect(),
+            Error::Status(err) => err
+                .meta
+                .error_code
+                .as_ref()
+                .is_some_and(NicoErrorCode::is_retryable),
+            Error::Json(_) => false,
+            #[cfg(feature = "downloader")]
+            Error::Io(_) => false,
+            #[cfg(feature = "downloader")]
+            Error::Downloader(..) => false,
+        }
+    }
+}
+
+/// Parse a `Retry-After` header in either of its valid forms: a delay in
+/// seconds, or an HTTP-date to wait until.
+fn parse_retry_after(response: &reqwest::Response) -> Option<Duration> {
+    let value = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?;
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    httpdate::parse_http_date(value)
+        .ok()
+        .and_then(|at| at.duration_since(std::time::SystemTime::now()).ok())
+}
+
+/// Builder for a [`NicoClient`].
+///
+/// By default a `reqwest::Client` is built from `cookie_store`, but
+/// [`NicoClientBuilder::http_client`] lets a caller supply a pre-built one
+/// instead, leaving TLS backend selection (and any other client-level
+/// configuration) up to them.
+pub struct NicoClientBuilder<C> {
+    cookie_store: Arc<C>,
+    http_client: Option<Client>,
+    frontend_id: String,
+    base_url: String,
+    retry: RetryConfig,
+}
+
+impl<C: CookieStore + 'static> NicoClientBuilder<C> {
+    /// Override the `X-Frontend-Id` header sent with every request.
+    pub fn frontend_id(mut self, frontend_id: impl Into<String>) -> Self {
+        self.frontend_id = frontend_id.into();
+        self
+    }
+
+    /// Override the nvapi base URL, e.g. to point at a mock server in tests.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Use a pre-built `reqwest::Client` instead of constructing one from
+    /// the cookie store.
+    pub fn http_client(mut self, http_client: Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Override the retry policy applied to transient nvapi failures.
+    pub fn retry_config(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn build(self) -> Result<NicoClient> {
+        let http = match self.http_client {
+            Some(http) => http,
+            None => Client::builder()
+                .cookie_provider(self.cookie_store)
+                .build()
+                .map_err(Error::Http)?,
+        };
+
+        Ok(NicoClient {
+            http,
+            frontend_id: self.frontend_id,
+            base_url: self.base_url,
+            retry: self.retry,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_pages_rounds_up_to_cover_remainder() {
+        assert_eq!(total_pages(1, 100), 1);
+        assert_eq!(total_pages(99, 100), 1);
+        assert_eq!(total_pages(101, 100), 2);
+        assert_eq!(total_pages(250, 100), 3);
+    }
+
+    #[test]
+    fn total_pages_handles_exact_multiples() {
+        assert_eq!(total_pages(100, 100), 1);
+        assert_eq!(total_pages(200, 100), 2);
+    }
+
+    #[test]
+    fn total_pages_of_empty_mylist_is_zero() {
+        assert_eq!(total_pages(0, 100), 0);
+    }
+}
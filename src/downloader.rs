@@ -0,0 +1,108 @@
+//! Optional integration with `yt-dlp` for acting on videos referenced by a
+//! mylist, enabled via the `downloader` feature.
+
+use tokio::process::Command;
+
+use crate::{Error, MylistDetail, Result, Video};
+
+/// Options for a single [`Downloader::download`] invocation.
+#[derive(Debug, Clone, Default)]
+pub struct DownloadOptions {
+    pub output_template: Option<String>,
+    pub format: Option<String>,
+    pub socket_timeout: Option<u32>,
+}
+
+/// Drives `yt-dlp` against videos referenced by a mylist, forwarding the
+/// crate's `user_session` cookies so age/login-gated videos resolve the
+/// same way they do through the nvapi calls.
+pub struct Downloader {
+    user_session: String,
+    user_session_secure: String,
+    binary: String,
+}
+
+impl Downloader {
+    pub fn new(user_session: impl Into<String>, user_session_secure: impl Into<String>) -> Self {
+        Self {
+            user_session: user_session.into(),
+            user_session_secure: user_session_secure.into(),
+            binary: "yt-dlp".to_string(),
+        }
+    }
+
+    /// Use a non-default `yt-dlp` executable, e.g. a full path.
+    pub fn binary(mut self, binary: impl Into<String>) -> Self {
+        self.binary = binary.into();
+        self
+    }
+
+    /// Run `yt-dlp -J` against `video` and return its parsed metadata
+    /// without downloading anything.
+    pub async fn extract_info(&self, video: &Video) -> Result<serde_json::Value> {
+        let output = Command::new(&self.binary)
+            .arg("-J")
+            .arg("--add-header")
+            .arg(self.cookie_header())
+            .arg(watch_url(video))
+            .output()
+            .await
+            .map_err(Error::Io)?;
+
+        if !output.status.success() {
+            return Err(Error::Downloader(output.status, stderr_of(&output)));
+        }
+
+        serde_json::from_slice(&output.stdout).map_err(Error::Json)
+    }
+
+    /// Download `video` with `yt-dlp`, applying `opts`.
+    pub async fn download(&self, video: &Video, opts: &DownloadOptions) -> Result<()> {
+        let mut command = Command::new(&self.binary);
+        command.arg("--add-header").arg(self.cookie_header());
+
+        if let Some(output_template) = &opts.output_template {
+            command.arg("-o").arg(output_template);
+        }
+        if let Some(format) = &opts.format {
+            command.arg("-f").arg(format);
+        }
+        if let Some(socket_timeout) = opts.socket_timeout {
+            command
+                .arg("--socket-timeout")
+                .arg(socket_timeout.to_string());
+        }
+
+        let output = command.arg(watch_url(video)).output().await.map_err(Error::Io)?;
+
+        if !output.status.success() {
+            return Err(Error::Downloader(output.status, stderr_of(&output)));
+        }
+
+        Ok(())
+    }
+
+    /// Run [`Downloader::download`] for every item in `mylist`.
+    pub async fn download_mylist(&self, mylist: &MylistDetail, opts: &DownloadOptions) -> Result<()> {
+        for item in &mylist.items {
+            self.download(&item.video, opts).await?;
+        }
+
+        Ok(())
+    }
+
+    fn cookie_header(&self) -> String {
+        format!(
+            "Cookie:user_session={}; user_session_secure={}",
+            self.user_session, self.user_session_secure
+        )
+    }
+}
+
+fn watch_url(video: &Video) -> String {
+    format!("https://www.nicovideo.jp/watch/{}", video.id)
+}
+
+fn stderr_of(output: &std::process::Output) -> String {
+    String::from_utf8_lossy(&output.stderr).trim().to_string()
+}
@@ -1,11 +1,20 @@
-use std::sync::Arc;
-
-use reqwest::{cookie::CookieStore, Client, Url};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "ts")]
 use ts_rs::TS;
 
+mod client;
+#[cfg(feature = "downloader")]
+pub mod downloader;
+mod retry;
+#[cfg(feature = "rss")]
+pub mod rss;
+#[cfg(feature = "search")]
+pub mod search;
+
+pub use client::{NicoClient, NicoClientBuilder};
+pub use retry::RetryConfig;
+
 type StdResult<T, E> = std::result::Result<T, E>;
 
 /// Result type used by this crate. This is equivalent
@@ -17,6 +26,11 @@ pub enum Error {
     Status(NicoError),
     Http(reqwest::Error),
     Json(serde_json::Error),
+    #[cfg(feature = "downloader")]
+    Io(std::io::Error),
+    /// `yt-dlp` exited unsuccessfully; carries its exit status and stderr.
+    #[cfg(feature = "downloader")]
+    Downloader(std::process::ExitStatus, String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -40,7 +54,113 @@ pub struct NicoError {
 #[serde(rename_all = "camelCase")]
 pub struct NicoMeta {
     pub status: usize,
-    pub error_code: Option<String>,
+    pub error_code: Option<NicoErrorCode>,
+}
+
+/// Known nvapi error codes, with an [`NicoErrorCode::Unknown`] catch-all so
+/// unrecognized codes still round-trip instead of failing to deserialize.
+///
+/// On the wire (and therefore for `ts-rs`) this is a bare string, the same
+/// as the `Option<String>` it replaces, so [`TS`] is implemented by hand
+/// below rather than derived — deriving would export it as a tagged union
+/// that doesn't match what [`Serialize`]/[`Deserialize`] actually produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NicoErrorCode {
+    InvalidParameter,
+    NotFound,
+    Unauthorized,
+    Forbidden,
+    Maintenance,
+    Unknown(String),
+}
+
+impl NicoErrorCode {
+    /// The raw nvapi string this code was parsed from (or would serialize
+    /// to), kept for forward-compat with codes this enum doesn't know about.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::InvalidParameter => "INVALID_PARAMETER",
+            Self::NotFound => "NOT_FOUND",
+            Self::Unauthorized => "UNAUTHORIZED",
+            Self::Forbidden => "FORBIDDEN",
+            Self::Maintenance => "MAINTENANCE",
+            Self::Unknown(raw) => raw,
+        }
+    }
+
+    /// Whether this code indicates the caller isn't authenticated or
+    /// lacks permission.
+    pub fn is_auth_error(&self) -> bool {
+        matches!(self, Self::Unauthorized | Self::Forbidden)
+    }
+
+    /// Whether a request that failed with this code is worth retrying.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Maintenance)
+    }
+}
+
+impl Serialize for NicoErrorCode {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NicoErrorCode {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Ok(match raw.as_str() {
+            "INVALID_PARAMETER" => Self::InvalidParameter,
+            "NOT_FOUND" => Self::NotFound,
+            "UNAUTHORIZED" => Self::Unauthorized,
+            "FORBIDDEN" => Self::Forbidden,
+            "MAINTENANCE" => Self::Maintenance,
+            _ => Self::Unknown(raw),
+        })
+    }
+}
+
+// Hand-written to match the plain-string wire format above: deriving `TS`
+// would treat `Unknown(String)` as a tagged union (`{ "Unknown": string }`)
+// instead of the bare `string` nvapi (and our `Serialize` impl) actually use.
+#[cfg(feature = "ts")]
+impl TS for NicoErrorCode {
+    type WithoutGenerics = Self;
+    type OptionInnerType = Self;
+
+    fn name() -> String {
+        "string".to_string()
+    }
+
+    fn inline() -> String {
+        "string".to_string()
+    }
+
+    fn inline_flattened() -> String {
+        "string".to_string()
+    }
+
+    fn decl() -> String {
+        unreachable!("NicoErrorCode inlines as `string`; it has no standalone declaration")
+    }
+
+    fn decl_concrete() -> String {
+        unreachable!("NicoErrorCode inlines as `string`; it has no standalone declaration")
+    }
+
+    fn dependencies() -> Vec<ts_rs::Dependency> {
+        Vec::new()
+    }
+
+    fn transparent() -> bool {
+        true
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -148,40 +268,6 @@ pub struct Thumbnail {
     pub n_hd_url: Option<String>,
 }
 
-pub async fn get_my_mylists<C: CookieStore + 'static>(
-    cookie_store: Arc<C>,
-    sample_item_count: usize,
-) -> Result<NicoResult<MylistsResponse>> {
-    let url = format!(
-        "https://nvapi.nicovideo.jp/v1/users/me/mylists?sampleItemCount={}",
-        sample_item_count
-    )
-    .parse::<Url>()
-    .expect("This is illegal");
-
-    let client = Client::builder()
-        .cookie_provider(cookie_store)
-        .build()
-        .map_err(Error::Http)?;
-    let response = client
-        .get(url)
-        .header("X-Frontend-Id", "6")
-        .send()
-        .await
-        .map_err(Error::Http)?;
-    let status_code = response.status();
-    let string = response.text().await.map_err(Error::Http)?;
-
-    if status_code.as_u16() > 299u16 {
-        let err: NicoError = serde_json::from_str(&string).map_err(Error::Json)?;
-        return Err(Error::Status(err));
-    }
-
-    let response = serde_json::from_str(&string).map_err(Error::Json)?;
-
-    Ok(response)
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "ts", derive(TS))]
 #[cfg_attr(feature = "ts", ts(export))]
@@ -210,69 +296,11 @@ pub struct MylistDetail {
     pub is_following: bool,
 }
 
-pub async fn get_mylist<C: CookieStore + 'static>(
-    cookie_store: Arc<C>,
-    id: usize,
-    page_size: usize,
-    page: usize,
-) -> Result<NicoResult<MylistResponse>> {
-    let url = format!(
-        "https://nvapi.nicovideo.jp/v1/users/me/mylists/{}?pageSize={}&page={}",
-        id, page_size, page
-    )
-    .parse::<Url>()
-    .expect("This is illegal");
-    let client = Client::builder()
-        .cookie_provider(cookie_store)
-        .build()
-        .map_err(Error::Http)?;
-    let response = client
-        .get(url)
-        .header("X-Frontend-Id", "6")
-        .send()
-        .await
-        .map_err(Error::Http)?;
-    let status_code = response.status();
-    let string = response.text().await.map_err(Error::Http)?;
-
-    if status_code.as_u16() > 299u16 {
-        let err: NicoError = serde_json::from_str(&string).map_err(Error::Json)?;
-        return Err(Error::Status(err));
-    }
-
-    let response = serde_json::from_str(&string).map_err(Error::Json)?;
-
-    Ok(response)
-}
-
-pub async fn get_mylist_all<C: CookieStore + 'static>(
-    cookie_store: Arc<C>,
-    id: usize,
-) -> Result<NicoResult<MylistResponse>> {
-    let mut first_mylist = get_mylist(cookie_store.clone(), id, 100, 1).await?;
-    if !first_mylist.data.mylist.has_next {
-        return Ok(first_mylist);
-    }
-
-    let mut page = 2;
-    let mut extend_items = Vec::new();
-    loop {
-        let next_mylist = get_mylist(cookie_store.clone(), id, 100, page).await?;
-        extend_items.extend(next_mylist.data.mylist.items);
-        if !next_mylist.data.mylist.has_next {
-            break;
-        }
-        page += 1;
-    }
-
-    first_mylist.data.mylist.items.extend(extend_items);
-
-    Ok(first_mylist)
-}
-
 #[cfg(test)]
 mod tests {
-    use reqwest::cookie::Jar;
+    use std::sync::Arc;
+
+    use reqwest::{cookie::Jar, Url};
 
     use super::*;
 
@@ -285,8 +313,7 @@ mod tests {
         assert_eq!(result, 4);
     }
 
-    #[tokio::test]
-    async fn get_my_mylists_works() {
+    fn test_client() -> NicoClient {
         let jar = Jar::default();
         jar.add_cookie_str(
             &format!(
@@ -296,38 +323,31 @@ mod tests {
             &Url::parse("https://nvapi.nicovideo.jp/").unwrap(),
         );
 
-        let result = get_my_mylists(Arc::new(jar), 4).await.unwrap();
+        NicoClient::builder(Arc::new(jar)).build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn get_my_mylists_works() {
+        let client = test_client();
+
+        let result = client.my_mylists(4).await.unwrap();
 
         println!("mylists len: {:?}", result.data.mylists.len());
     }
 
     #[tokio::test]
     async fn get_mylist_works() {
-        let jar = Jar::default();
-        jar.add_cookie_str(
-            &format!(
-                "user_session={}; user_session_secure={};",
-                USER_SESSION, USER_SESSION_SECURE
-            ),
-            &Url::parse("https://nvapi.nicovideo.jp/").unwrap(),
-        );
+        let client = test_client();
 
-        let result = get_mylist(Arc::new(jar), 71381719, 100, 1).await.unwrap();
+        let result = client.mylist(71381719, 100, 1).await.unwrap();
         println!("mylist len: {:?}", result.data.mylist.items.len());
     }
 
     #[tokio::test]
     async fn get_mylist_all_works() {
-        let jar = Jar::default();
-        jar.add_cookie_str(
-            &format!(
-                "user_session={}; user_session_secure={};",
-                USER_SESSION, USER_SESSION_SECURE
-            ),
-            &Url::parse("https://nvapi.nicovideo.jp/").unwrap(),
-        );
+        let client = test_client();
 
-        let result = get_mylist_all(Arc::new(jar), 71381719).await.unwrap();
+        let result = client.mylist_all(71381719).await.unwrap();
         println!("mylist len: {:?}", result.data.mylist.items.len());
     }
 }
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+/// Retry policy applied by [`crate::NicoClient`] to transient nvapi
+/// failures: connection/timeout errors, HTTP 429/500/502/503 responses,
+/// and error responses whose nvapi error code is retryable (see
+/// [`crate::NicoErrorCode::is_retryable`]), whatever their status. Other
+/// failures (e.g. a 4xx besides 429 with any other code) fail immediately.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryConfig {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Delay before the `attempt`-th retry (0-indexed), honoring
+    /// `retry_after` (from a `Retry-After` header) as a lower bound.
+    pub(crate) fn delay_for(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        let backoff = self
+            .base_delay
+            .saturating_mul(1u32 << attempt.min(16))
+            .min(self.max_delay);
+        let delay = backoff + jitter(backoff / 4);
+
+        match retry_after {
+            Some(retry_after) => delay.max(retry_after),
+            None => delay,
+        }
+    }
+}
+
+/// A small amount of jitter, up to `max`, derived from the current time so
+/// retrying callers don't all wake up in lockstep.
+fn jitter(max: Duration) -> Duration {
+    let max_nanos = max.as_nanos().max(1) as u64;
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos() as u64;
+
+    Duration::from_nanos(nanos % max_nanos)
+}
+
+pub(crate) fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 429 | 500 | 502 | 503)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> RetryConfig {
+        RetryConfig {
+            max_retries: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+        }
+    }
+
+    #[test]
+    fn delay_grows_with_attempt() {
+        let config = config();
+
+        let first = config.delay_for(0, None);
+        let second = config.delay_for(1, None);
+        let third = config.delay_for(2, None);
+
+        assert!(first >= config.base_delay);
+        assert!(second >= config.base_delay * 2);
+        assert!(third >= config.base_delay * 4);
+    }
+
+    #[test]
+    fn delay_is_capped_at_max_delay() {
+        let config = config();
+
+        let delay = config.delay_for(16, None);
+
+        // Capped backoff plus up to a quarter of it in jitter.
+        assert!(delay >= config.max_delay);
+        assert!(delay <= config.max_delay + config.max_delay / 4);
+    }
+
+    #[test]
+    fn retry_after_is_a_lower_bound() {
+        let config = config();
+        let retry_after = Duration::from_secs(10);
+
+        let delay = config.delay_for(0, Some(retry_after));
+
+        assert!(delay >= retry_after);
+    }
+
+    #[test]
+    fn retry_after_shorter_than_backoff_does_not_shrink_delay() {
+        let config = config();
+
+        let without = config.delay_for(0, None);
+        let with_short_retry_after = config.delay_for(0, Some(Duration::from_nanos(1)));
+
+        assert!(with_short_retry_after >= without.min(with_short_retry_after));
+        assert!(with_short_retry_after >= config.base_delay);
+    }
+
+    #[test]
+    fn is_retryable_status_matches_transient_codes() {
+        assert!(is_retryable_status(429));
+        assert!(is_retryable_status(500));
+        assert!(is_retryable_status(502));
+        assert!(is_retryable_status(503));
+        assert!(!is_retryable_status(400));
+        assert!(!is_retryable_status(404));
+        assert!(!is_retryable_status(200));
+    }
+}
@@ -0,0 +1,211 @@
+//! Render a mylist as an RSS 2.0 feed, enabled via the `rss` feature.
+
+use std::io::Cursor;
+
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::Writer;
+
+use crate::{Item, MylistDetail};
+
+/// Render `mylist` as an RSS 2.0 document, one `<item>` per entry, so it
+/// can be self-hosted as a subscribable feed.
+pub fn to_rss(mylist: &MylistDetail) -> String {
+    let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2);
+
+    write_start(
+        &mut writer,
+        "rss",
+        &[
+            ("version", "2.0"),
+            ("xmlns:media", "http://search.yahoo.com/mrss/"),
+        ],
+    );
+    write_start(&mut writer, "channel", &[]);
+    write_text(&mut writer, "title", &mylist.name);
+    write_text(&mut writer, "description", &mylist.description);
+    write_text(
+        &mut writer,
+        "link",
+        &format!("https://www.nicovideo.jp/mylist/{}", mylist.id),
+    );
+
+    for item in &mylist.items {
+        write_item(&mut writer, item);
+    }
+
+    write_end(&mut writer, "channel");
+    write_end(&mut writer, "rss");
+
+    String::from_utf8(writer.into_inner().into_inner()).expect("RSS output is valid UTF-8")
+}
+
+fn write_item(writer: &mut Writer<Cursor<Vec<u8>>>, item: &Item) {
+    write_start(writer, "item", &[]);
+    write_text(writer, "title", &item.video.title);
+    write_text(
+        writer,
+        "link",
+        &format!("https://www.nicovideo.jp/watch/{}", item.video.id),
+    );
+    write_text(writer, "description", &item.video.short_description);
+    write_text(writer, "pubDate", &to_rfc822(&item.added_at));
+    write_empty(
+        writer,
+        "enclosure",
+        &[
+            ("url", item.video.thumbnail.url.as_str()),
+            ("type", "image/jpeg"),
+        ],
+    );
+    write_empty(
+        writer,
+        "media:thumbnail",
+        &[("url", item.video.thumbnail.url.as_str())],
+    );
+    write_end(writer, "item");
+}
+
+fn write_start(writer: &mut Writer<Cursor<Vec<u8>>>, name: &str, attrs: &[(&str, &str)]) {
+    let mut start = BytesStart::new(name);
+    start.extend_attributes(attrs.iter().copied());
+    writer
+        .write_event(Event::Start(start))
+        .expect("writing RSS XML failed");
+}
+
+fn write_end(writer: &mut Writer<Cursor<Vec<u8>>>, name: &str) {
+    writer
+        .write_event(Event::End(BytesEnd::new(name)))
+        .expect("writing RSS XML failed");
+}
+
+fn write_empty(writer: &mut Writer<Cursor<Vec<u8>>>, name: &str, attrs: &[(&str, &str)]) {
+    let mut start = BytesStart::new(name);
+    start.extend_attributes(attrs.iter().copied());
+    writer
+        .write_event(Event::Empty(start))
+        .expect("writing RSS XML failed");
+}
+
+fn write_text(writer: &mut Writer<Cursor<Vec<u8>>>, name: &str, text: &str) {
+    write_start(writer, name, &[]);
+    writer
+        .write_event(Event::Text(BytesText::new(text)))
+        .expect("writing RSS XML failed");
+    write_end(writer, name);
+}
+
+/// Convert the nvapi's ISO 8601 `added_at` timestamp to RFC 822, falling
+/// back to the raw string if it doesn't parse.
+fn to_rfc822(added_at: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(added_at)
+        .map(|dt| dt.to_rfc2822())
+        .unwrap_or_else(|_| added_at.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Count, Owner, Thumbnail, Video};
+
+    use super::*;
+
+    fn owner() -> Owner {
+        Owner {
+            owner_type: "user".to_string(),
+            id: Some("1".to_string()),
+            name: Some("someone".to_string()),
+            icon_url: None,
+        }
+    }
+
+    fn mylist() -> MylistDetail {
+        let video = Video {
+            video_type: "essential".to_string(),
+            id: "sm12345".to_string(),
+            title: "a video".to_string(),
+            registered_at: "2024-01-01T00:00:00+09:00".to_string(),
+            count: Count {
+                view: 1,
+                comment: 2,
+                mylist: 3,
+                like: 4,
+            },
+            thumbnail: Thumbnail {
+                url: "https://example.com/thumb.jpg".to_string(),
+                middle_url: None,
+                large_url: None,
+                listing_url: None,
+                n_hd_url: None,
+            },
+            duration: 60,
+            short_description: "a short description".to_string(),
+            latest_comment_summary: String::new(),
+            is_channel_video: false,
+            is_payment_required: false,
+            playback_position: None,
+            owner: owner(),
+            require_sensitive_masking: false,
+            video_live: None,
+            n_9d091f87: false,
+            n_acf68865: false,
+        };
+
+        MylistDetail {
+            id: 1,
+            name: "my mylist".to_string(),
+            description: "a mylist".to_string(),
+            default_sort_key: "addedAt".to_string(),
+            default_sort_order: "desc".to_string(),
+            items: vec![Item {
+                item_id: 1,
+                watch_id: "sm12345".to_string(),
+                description: String::new(),
+                added_at: "2024-01-02T03:04:05+09:00".to_string(),
+                status: "public".to_string(),
+                video,
+            }],
+            total_item_count: 1,
+            has_next: false,
+            is_public: true,
+            owner: owner(),
+            has_invisible_items: false,
+            follower_count: 0,
+            is_following: false,
+        }
+    }
+
+    #[test]
+    fn to_rss_emits_well_formed_xml() {
+        let xml = to_rss(&mylist());
+
+        let mut reader = quick_xml::Reader::from_str(&xml);
+        loop {
+            match reader.read_event().expect("RSS output must be valid XML") {
+                quick_xml::events::Event::Eof => break,
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn to_rss_declares_the_media_namespace() {
+        let xml = to_rss(&mylist());
+
+        assert!(xml.contains(r#"xmlns:media="http://search.yahoo.com/mrss/""#));
+    }
+
+    #[test]
+    fn to_rss_maps_channel_and_item_fields() {
+        let xml = to_rss(&mylist());
+
+        assert!(xml.contains("<title>my mylist</title>"));
+        assert!(xml.contains("<title>a video</title>"));
+        assert!(xml.contains("<link>https://www.nicovideo.jp/watch/sm12345</link>"));
+        assert!(xml.contains("<media:thumbnail url=\"https://example.com/thumb.jpg\"/>"));
+    }
+
+    #[test]
+    fn to_rfc822_falls_back_to_raw_string_on_unparseable_input() {
+        assert_eq!(to_rfc822("not a date"), "not a date");
+    }
+}
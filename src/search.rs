@@ -0,0 +1,148 @@
+//! Niconico video search, enabled via the `search` feature.
+//!
+//! **Experimental.** The `/v2/search/video` endpoint path, its response
+//! envelope, and the shape of each result haven't been confirmed against
+//! the real nvapi/snapshot search API from this tree — unlike the mylist
+//! calls, there's no working access to exercise it against. A dedicated
+//! [`SearchVideo`] type is used instead of reusing [`crate::Video`]
+//! directly, so that if this guess turns out wrong it's this module's
+//! type that needs fixing, not the verified mylist model.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+use reqwest::Url;
+
+use crate::{Count, NicoClient, NicoResult, Owner, Result, Thumbnail};
+
+/// Key to sort search results by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    ViewCount,
+    CommentCount,
+    PostedDate,
+}
+
+impl SortKey {
+    fn as_query_value(&self) -> &'static str {
+        match self {
+            Self::ViewCount => "viewCount",
+            Self::CommentCount => "commentCount",
+            Self::PostedDate => "registeredAt",
+        }
+    }
+}
+
+/// Direction to apply a [`SortKey`] in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    fn as_query_value(&self) -> &'static str {
+        match self {
+            Self::Ascending => "asc",
+            Self::Descending => "desc",
+        }
+    }
+}
+
+/// Parameters for [`NicoClient::search_videos`].
+#[derive(Debug, Clone)]
+pub struct SearchParams {
+    pub sort_key: Option<SortKey>,
+    pub sort_order: Option<SortOrder>,
+    pub tags: Vec<String>,
+    pub page: usize,
+    pub page_size: usize,
+}
+
+impl Default for SearchParams {
+    fn default() -> Self {
+        Self {
+            sort_key: None,
+            sort_order: None,
+            tags: Vec::new(),
+            page: 1,
+            page_size: 20,
+        }
+    }
+}
+
+/// A search result. Shaped like [`crate::Video`] for the fields search and
+/// mylists plausibly share, but kept as its own type (see the module
+/// docs) and with the fields that are specifically mylist-only on `Video`
+/// (`latestCommentSummary`, `requireSensitiveMasking`, `playbackPosition`,
+/// `videoLive`, and the two numeric-key flags) left out entirely rather
+/// than guessed at.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "ts", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct SearchVideo {
+    #[cfg_attr(feature = "ts", ts(rename = "type"))]
+    #[serde(rename(deserialize = "type"))]
+    pub video_type: String,
+    pub id: String,
+    pub title: String,
+    pub registered_at: String,
+    pub count: Count,
+    pub thumbnail: Thumbnail,
+    pub duration: usize,
+    pub short_description: String,
+    pub is_channel_video: bool,
+    pub is_payment_required: bool,
+    pub owner: Owner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "ts", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResponse {
+    #[serde(default)]
+    pub items: Vec<SearchVideo>,
+    pub total_count: usize,
+    pub has_next: bool,
+}
+
+impl NicoClient {
+    /// Search videos matching `query`.
+    ///
+    /// Experimental: see the [`crate::search`] module docs — the endpoint
+    /// and response shape are unverified, so failures here are more
+    /// likely to be this method being wrong than the caller.
+    pub async fn search_videos(
+        &self,
+        query: &str,
+        params: &SearchParams,
+    ) -> Result<NicoResult<SearchResponse>> {
+        let mut url = format!("{}/v2/search/video", self.base_url())
+            .parse::<Url>()
+            .expect("This is illegal");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            query_pairs
+                .append_pair("q", query)
+                .append_pair("page", &params.page.to_string())
+                .append_pair("pageSize", &params.page_size.to_string());
+
+            if let Some(sort_key) = params.sort_key {
+                query_pairs.append_pair("sortKey", sort_key.as_query_value());
+            }
+            if let Some(sort_order) = params.sort_order {
+                query_pairs.append_pair("sortOrder", sort_order.as_query_value());
+            }
+            for tag in &params.tags {
+                query_pairs.append_pair("tag", tag);
+            }
+        }
+
+        self.get(url).await
+    }
+}